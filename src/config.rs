@@ -0,0 +1,61 @@
+//! Runtime configuration for [`Watcher`](crate::Watcher) implementations.
+
+/// Indicates whether only the provided directory, or its sub-directories as well, should be
+/// watched.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RecursiveMode {
+    /// Watch all sub-directories as well, including new ones added after the watch begins.
+    Recursive,
+    /// Watch only the immediate directory.
+    NonRecursive,
+}
+
+impl RecursiveMode {
+    pub(crate) fn is_recursive(self) -> bool {
+        matches!(self, RecursiveMode::Recursive)
+    }
+}
+
+/// Runtime configuration options accepted by [`Watcher::configure`](crate::Watcher::configure).
+///
+/// Not every option is supported by every backend; unsupported options make `configure` return
+/// `Ok(false)`.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Config {
+    /// Enable emitting precise, richly-classified events instead of the default coarse ones.
+    PreciseEvents(bool),
+    /// Suppress events for paths matching any of these glob/gitignore-style patterns, in
+    /// addition to any filter passed to
+    /// [`watch_with_filter`](crate::Watcher::watch_with_filter) for a specific path.
+    ///
+    /// See [`PathFilter`](crate::PathFilter) for the supported pattern syntax.
+    IgnorePatterns(Vec<String>),
+    /// Bound how many events a backend may buffer internally in a single burst before
+    /// [`OverflowPolicy`] applies, instead of letting memory use grow unboundedly during e.g. a
+    /// large `rm -rf` or checkout.
+    ChannelCapacity(usize),
+    /// How to behave once a burst exceeds [`Config::ChannelCapacity`].
+    OverflowPolicy(OverflowPolicy),
+}
+
+/// What a backend should do when a burst of events exceeds its configured
+/// [`Config::ChannelCapacity`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum OverflowPolicy {
+    /// Block the watcher's internal thread until there is room to forward the backlog.
+    Block,
+    /// Drop the oldest buffered events in the burst to make room for newer ones.
+    DropOldest,
+    /// Collapse the whole burst into a single synthetic [`EventKind::Rescan`](crate::EventKind::Rescan)
+    /// event telling the consumer to re-stat the affected subtree, mirroring how kernel event
+    /// queues signal overflow.
+    Rescan,
+}
+
+impl Default for OverflowPolicy {
+    fn default() -> Self {
+        OverflowPolicy::Block
+    }
+}
@@ -0,0 +1,250 @@
+//! Debouncing support for [`Watcher::new`](crate::Watcher::new).
+//!
+//! Editors frequently write a temporary file and rename it over the target, or issue several
+//! rapid writes for one logical save. This module merges bursts of [`RawEvent`]s into a single
+//! coalesced [`Event`] per path, emitted once activity on that path has settled down for a
+//! configured `delay`.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossbeam_channel::{Receiver, Sender};
+
+use crate::event::{Event, EventKind};
+use crate::raw_event::RawEvent;
+
+struct Pending {
+    kind: EventKind,
+    last_seen: Instant,
+}
+
+/// Spawn the background thread that merges `RawEvent`s arriving on `rx` into coalesced
+/// [`Event`]s sent on `tx`, one per path, once `delay` has elapsed since that path was last
+/// touched.
+///
+/// A path's pending event can also be flushed immediately, ahead of its `delay`, by sending that
+/// path on `flush_rx` -- used by [`Watcher::unwatch`](crate::Watcher::unwatch) implementations
+/// that opt into [`Watcher::register_debounce_flush`](crate::Watcher::register_debounce_flush) so
+/// an unwatched path's in-flight event isn't left to wait out its full timeout for no reason.
+///
+/// The thread exits -- flushing any events still pending -- once `rx` disconnects, which happens
+/// when the watcher that owns the other end of the raw channel is dropped or stops forwarding.
+pub(crate) fn spawn(
+    rx: Receiver<RawEvent>,
+    flush_rx: Receiver<PathBuf>,
+    tx: Sender<Event>,
+    delay: Duration,
+) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        let mut pending: HashMap<PathBuf, Pending> = HashMap::new();
+        // Once `flush_rx` disconnects (e.g. the backend doesn't override
+        // `register_debounce_flush`), select on `never()` instead: a disconnected receiver is
+        // ready on every iteration and would keep the loop from ever blocking.
+        let never = crossbeam_channel::never();
+        let mut flush_open = true;
+
+        loop {
+            let flush = if flush_open { &flush_rx } else { &never };
+            crossbeam_channel::select! {
+                recv(rx) -> msg => match msg {
+                    Ok(raw) => merge(&mut pending, raw),
+                    Err(_) => {
+                        flush_all(&mut pending, &tx);
+                        return;
+                    }
+                },
+                recv(flush) -> msg => match msg {
+                    Ok(path) => flush_path(&mut pending, &tx, &path),
+                    Err(_) => flush_open = false,
+                },
+                default(next_wait(&pending, delay)) => {}
+            }
+            flush_ready(&mut pending, &tx, delay);
+        }
+    })
+}
+
+/// How long to block on `rx` before checking for ready-to-flush paths again.
+///
+/// Waiting a flat `delay` every iteration would let a quiet path sit unflushed for arbitrarily
+/// longer than `delay` whenever other paths keep receiving events in the meantime, since each
+/// such event would reset the wait to a fresh `delay` without ever re-checking the quiet path's
+/// actual deadline. Instead, wake up exactly when the earliest pending path is due.
+fn next_wait(pending: &HashMap<PathBuf, Pending>, delay: Duration) -> Duration {
+    pending
+        .values()
+        .map(|p| delay.saturating_sub(p.last_seen.elapsed()))
+        .min()
+        .unwrap_or(delay)
+}
+
+/// Fold a newly observed raw event into the pending, coalesced state for its path.
+fn merge(pending: &mut HashMap<PathBuf, Pending>, raw: RawEvent) {
+    let path = match raw.path {
+        Some(path) => path,
+        None => return,
+    };
+    let kind = raw.op.unwrap_or_else(crate::raw_event::Op::empty).classify();
+
+    pending
+        .entry(path)
+        .and_modify(|p| {
+            p.kind = merge_kind(p.kind, kind);
+            p.last_seen = Instant::now();
+        })
+        .or_insert_with(|| Pending { kind, last_seen: Instant::now() });
+}
+
+/// Merge two observed kinds for the same path into the kind that best describes the net effect,
+/// e.g. a create followed by repeated modifies is still just a create.
+///
+/// [`EventKind::Rescan`] takes precedence over everything else: it means the backend dropped
+/// events for this path during a burst, so whatever ordinary kind we also observed in the same
+/// window can't be trusted to describe the full picture, and must not silently swallow the "go
+/// re-stat this subtree" signal.
+fn merge_kind(previous: EventKind, next: EventKind) -> EventKind {
+    match (previous, next) {
+        (EventKind::Rescan, _) | (_, EventKind::Rescan) => EventKind::Rescan,
+        (EventKind::Remove, _) | (_, EventKind::Remove) => EventKind::Remove,
+        (EventKind::Create, _) | (_, EventKind::Create) => EventKind::Create,
+        (EventKind::Modify, _) | (_, EventKind::Modify) => EventKind::Modify,
+        _ => next,
+    }
+}
+
+fn flush_ready(pending: &mut HashMap<PathBuf, Pending>, tx: &Sender<Event>, delay: Duration) {
+    let ready: Vec<PathBuf> = pending
+        .iter()
+        .filter(|(_, p)| p.last_seen.elapsed() >= delay)
+        .map(|(path, _)| path.clone())
+        .collect();
+
+    for path in ready {
+        if let Some(p) = pending.remove(&path) {
+            let _ = tx.send(Event::new(p.kind, path));
+        }
+    }
+}
+
+/// Flush `path`'s pending event immediately, if it has one, ahead of its normal `delay` timeout.
+fn flush_path(pending: &mut HashMap<PathBuf, Pending>, tx: &Sender<Event>, path: &Path) {
+    if let Some(p) = pending.remove(path) {
+        let _ = tx.send(Event::new(p.kind, path.to_path_buf()));
+    }
+}
+
+fn flush_all(pending: &mut HashMap<PathBuf, Pending>, tx: &Sender<Event>) {
+    for (path, p) in pending.drain() {
+        let _ = tx.send(Event::new(p.kind, path));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_kind_prefers_remove_over_everything() {
+        assert_eq!(merge_kind(EventKind::Create, EventKind::Remove), EventKind::Remove);
+        assert_eq!(merge_kind(EventKind::Remove, EventKind::Modify), EventKind::Remove);
+    }
+
+    #[test]
+    fn merge_kind_prefers_create_over_modify() {
+        assert_eq!(merge_kind(EventKind::Create, EventKind::Modify), EventKind::Create);
+        assert_eq!(merge_kind(EventKind::Modify, EventKind::Create), EventKind::Create);
+    }
+
+    #[test]
+    fn merge_kind_collapses_repeated_modifies() {
+        assert_eq!(merge_kind(EventKind::Modify, EventKind::Modify), EventKind::Modify);
+    }
+
+    #[test]
+    fn merge_kind_prefers_rescan_over_everything_else() {
+        assert_eq!(merge_kind(EventKind::Rescan, EventKind::Modify), EventKind::Rescan);
+        assert_eq!(merge_kind(EventKind::Create, EventKind::Rescan), EventKind::Rescan);
+        assert_eq!(merge_kind(EventKind::Rescan, EventKind::Remove), EventKind::Rescan);
+    }
+
+    #[test]
+    fn next_wait_uses_earliest_pending_deadline() {
+        let delay = Duration::from_millis(100);
+        let mut pending = HashMap::new();
+        pending.insert(
+            PathBuf::from("/a"),
+            Pending { kind: EventKind::Modify, last_seen: Instant::now() },
+        );
+
+        // A path touched 80ms ago with a 100ms delay should only need ~20ms more, not a flat
+        // 100ms -- otherwise a quiet path can be starved by other paths resetting the wait.
+        let touched_at = Instant::now() - Duration::from_millis(80);
+        pending.get_mut(&PathBuf::from("/a")).unwrap().last_seen = touched_at;
+
+        let wait = next_wait(&pending, delay);
+        assert!(wait <= Duration::from_millis(25), "expected a short wait, got {:?}", wait);
+    }
+
+    #[test]
+    fn next_wait_falls_back_to_delay_when_idle() {
+        let delay = Duration::from_millis(50);
+        let pending = HashMap::new();
+        assert_eq!(next_wait(&pending, delay), delay);
+    }
+
+    #[test]
+    fn flush_ready_only_emits_paths_past_their_deadline() {
+        let delay = Duration::from_millis(20);
+        let (tx, rx) = crossbeam_channel::unbounded();
+        let mut pending = HashMap::new();
+        pending.insert(
+            PathBuf::from("/stale"),
+            Pending { kind: EventKind::Modify, last_seen: Instant::now() - Duration::from_millis(30) },
+        );
+        pending.insert(
+            PathBuf::from("/fresh"),
+            Pending { kind: EventKind::Create, last_seen: Instant::now() },
+        );
+
+        flush_ready(&mut pending, &tx, delay);
+
+        let flushed = rx.try_recv().unwrap();
+        assert_eq!(flushed.paths, vec![PathBuf::from("/stale")]);
+        assert!(pending.contains_key(&PathBuf::from("/fresh")));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn flush_path_emits_only_the_named_path_regardless_of_its_deadline() {
+        let (tx, rx) = crossbeam_channel::unbounded();
+        let mut pending = HashMap::new();
+        pending.insert(
+            PathBuf::from("/just-touched"),
+            Pending { kind: EventKind::Modify, last_seen: Instant::now() },
+        );
+        pending.insert(
+            PathBuf::from("/other"),
+            Pending { kind: EventKind::Create, last_seen: Instant::now() },
+        );
+
+        flush_path(&mut pending, &tx, Path::new("/just-touched"));
+
+        let flushed = rx.try_recv().unwrap();
+        assert_eq!(flushed.paths, vec![PathBuf::from("/just-touched")]);
+        assert!(!pending.contains_key(&PathBuf::from("/just-touched")));
+        assert!(pending.contains_key(&PathBuf::from("/other")));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn flush_path_is_a_no_op_for_an_unknown_path() {
+        let (tx, rx) = crossbeam_channel::unbounded();
+        let mut pending: HashMap<PathBuf, Pending> = HashMap::new();
+
+        flush_path(&mut pending, &tx, Path::new("/never-seen"));
+
+        assert!(rx.try_recv().is_err());
+    }
+}
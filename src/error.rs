@@ -0,0 +1,70 @@
+//! Error and result types returned by [`Watcher`](crate::Watcher) implementations.
+
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// Result alias used throughout this crate.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The kind of error that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A generic error, with a human-readable description.
+    Generic(String),
+    /// An I/O error from the underlying platform API.
+    Io(io::Error),
+    /// The given path could not be found.
+    PathNotFound,
+    /// Attempted to unwatch a path that isn't currently being watched.
+    WatchNotFound,
+    /// The platform's watch limit (e.g. inotify's `max_user_watches`) was exceeded.
+    MaxFilesWatch,
+}
+
+/// An error produced while watching, configuring, or debouncing.
+#[derive(Debug)]
+pub struct Error {
+    /// The kind of error.
+    pub kind: ErrorKind,
+    /// The paths the error relates to, if any.
+    pub paths: Vec<PathBuf>,
+}
+
+impl Error {
+    /// Create a new error of `kind`, with no associated paths.
+    pub fn new(kind: ErrorKind) -> Self {
+        Error { kind, paths: Vec::new() }
+    }
+
+    /// Attach a path to this error.
+    pub fn add_path(mut self, path: PathBuf) -> Self {
+        self.paths.push(path);
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ErrorKind::Generic(msg) => write!(f, "{}", msg)?,
+            ErrorKind::Io(err) => write!(f, "I/O error: {}", err)?,
+            ErrorKind::PathNotFound => write!(f, "path not found")?,
+            ErrorKind::WatchNotFound => write!(f, "watch not found")?,
+            ErrorKind::MaxFilesWatch => write!(f, "too many watches")?,
+        }
+        if !self.paths.is_empty() {
+            write!(f, " ({:?})", self.paths)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::new(ErrorKind::Io(err))
+    }
+}
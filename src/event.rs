@@ -0,0 +1,52 @@
+//! Event types emitted by a [`Watcher`](crate::Watcher).
+
+use std::path::PathBuf;
+
+use crate::error::{Error, ErrorKind};
+use crate::raw_event::RawEvent;
+use crate::Result;
+
+/// A high-level classification of what kind of change occurred.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum EventKind {
+    /// A path was created.
+    Create,
+    /// A path's content or metadata was modified.
+    Modify,
+    /// A path was removed.
+    Remove,
+    /// A backend had to drop events for this path during a burst; re-stat it to find out what
+    /// actually happened. See [`Config::OverflowPolicy`](crate::Config::OverflowPolicy).
+    Rescan,
+    /// A kind of event that doesn't fit the other variants, or whose kind is unknown.
+    Any,
+}
+
+/// A coalesced, high-level filesystem change notification.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Event {
+    /// The kind of change that occurred.
+    pub kind: EventKind,
+    /// The paths the event is about.
+    pub paths: Vec<PathBuf>,
+}
+
+impl Event {
+    /// Create a new event of `kind` affecting a single `path`.
+    pub fn new(kind: EventKind, path: PathBuf) -> Self {
+        Event { kind, paths: vec![path] }
+    }
+
+    /// Convert a [`RawEvent`] into an [`Event`], classifying its [`Op`](crate::Op) flags.
+    ///
+    /// Fails if the raw event carries no path, since a path-less event can't be attributed to
+    /// anything a caller could act on.
+    pub(crate) fn try_from_raw(raw: RawEvent) -> Result<Event> {
+        let path = raw
+            .path
+            .ok_or_else(|| Error::new(ErrorKind::Generic("raw event had no path".into())))?;
+        let kind = raw.op.unwrap_or_else(crate::raw_event::Op::empty).classify();
+        Ok(Event::new(kind, path))
+    }
+}
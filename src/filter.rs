@@ -0,0 +1,146 @@
+//! Glob/gitignore-style path filtering for suppressing uninteresting watch events.
+
+use std::path::Path;
+
+use crate::error::{Error, ErrorKind};
+use crate::Result;
+
+/// A compiled set of glob patterns used to suppress events for paths that match.
+///
+/// Patterns follow a small subset of gitignore syntax: a bare pattern with no `/` matches any
+/// path segment anywhere in the path; a pattern containing `/` is matched against consecutive
+/// segments, where `**` matches any number of segments; a trailing `/` restricts the pattern to
+/// directories.
+#[derive(Clone, Debug, Default)]
+pub struct PathFilter {
+    patterns: Vec<Pattern>,
+}
+
+#[derive(Clone, Debug)]
+struct Pattern {
+    segments: Vec<String>,
+    dir_only: bool,
+}
+
+impl PathFilter {
+    /// Compile `patterns` into a [`PathFilter`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any pattern is empty.
+    pub fn new<I, S>(patterns: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut compiled = Vec::new();
+        for pattern in patterns {
+            let pattern = pattern.as_ref();
+            if pattern.trim().is_empty() {
+                return Err(Error::new(ErrorKind::Generic("empty ignore pattern".into())));
+            }
+            let dir_only = pattern.ends_with('/');
+            let trimmed = pattern.trim_end_matches('/');
+            let segments = trimmed.split('/').map(str::to_string).collect();
+            compiled.push(Pattern { segments, dir_only });
+        }
+        Ok(PathFilter { patterns: compiled })
+    }
+
+    /// A filter that ignores nothing.
+    pub fn empty() -> Self {
+        PathFilter { patterns: Vec::new() }
+    }
+
+    /// Returns `true` if `self` has no patterns to match against.
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// Returns `true` if `path` matches any of the filter's patterns, and events for it should
+    /// therefore be suppressed.
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        let is_dir = path.is_dir();
+        let segments: Vec<&str> = path.iter().filter_map(|s| s.to_str()).collect();
+
+        self.patterns.iter().any(|pattern| {
+            if pattern.dir_only && !is_dir {
+                return false;
+            }
+            match_segments(&pattern.segments, &segments)
+        })
+    }
+}
+
+fn match_segments(pattern: &[String], path: &[&str]) -> bool {
+    // A single-segment pattern has no leading slash and so anchors anywhere in the path, as in
+    // gitignore.
+    if pattern.len() == 1 {
+        return path.iter().any(|segment| glob_match(&pattern[0], segment));
+    }
+
+    (0..=path.len().saturating_sub(pattern.len())).any(|start| match_from(pattern, &path[start..]))
+}
+
+fn match_from(pattern: &[String], path: &[&str]) -> bool {
+    match (pattern.first(), path.first()) {
+        (None, _) => true,
+        (Some(p), _) if p == "**" => {
+            (0..=path.len()).any(|skip| match_from(&pattern[1..], &path[skip..]))
+        }
+        (Some(_), None) => false,
+        (Some(p), Some(segment)) => glob_match(p, segment) && match_from(&pattern[1..], &path[1..]),
+    }
+}
+
+fn glob_match(pattern: &str, value: &str) -> bool {
+    fn inner(pattern: &[u8], value: &[u8]) -> bool {
+        match (pattern.first(), value.first()) {
+            (None, None) => true,
+            (Some(b'*'), _) => (0..=value.len()).any(|i| inner(&pattern[1..], &value[i..])),
+            (Some(&p), Some(&v)) if p == v => inner(&pattern[1..], &value[1..]),
+            _ => false,
+        }
+    }
+    inner(pattern.as_bytes(), value.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_match_handles_star() {
+        assert!(glob_match("*.tmp", "foo.tmp"));
+        assert!(glob_match("*", "anything"));
+        assert!(!glob_match("*.tmp", "foo.rs"));
+    }
+
+    #[test]
+    fn match_segments_anchors_bare_patterns_anywhere() {
+        let pattern = vec!["target".to_string()];
+        assert!(match_segments(&pattern, &["src", "target", "debug"]));
+        assert!(!match_segments(&pattern, &["src", "lib.rs"]));
+    }
+
+    #[test]
+    fn match_segments_handles_double_star() {
+        let pattern: Vec<String> = vec!["**".into(), "*.swp".into()];
+        assert!(match_segments(&pattern, &["src", "nested", "file.swp"]));
+        assert!(match_segments(&pattern, &["file.swp"]));
+        assert!(!match_segments(&pattern, &["src", "file.rs"]));
+    }
+
+    #[test]
+    fn is_ignored_respects_dir_only_patterns() {
+        let filter = PathFilter::new(["target/"]).unwrap();
+        // A nonexistent path is never reported as a directory, so a dir-only pattern shouldn't
+        // match it.
+        assert!(!filter.is_ignored(Path::new("/no/such/target")));
+    }
+
+    #[test]
+    fn new_rejects_empty_patterns() {
+        assert!(PathFilter::new([""]).is_err());
+    }
+}
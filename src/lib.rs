@@ -50,7 +50,7 @@
 //!
 //! ```
 //! # use crossbeam_channel::unbounded;
-//! # use notify::{Watcher, RecommendedWatcher, Result, watcher};
+//! # use notify::{Watcher, RecommendedWatcher, Result};
 //! # use std::time::Duration;
 //! #
 //! # fn main() -> Result<()> {
@@ -92,16 +92,39 @@
 //! #     Ok(())
 //! # }
 //! ```
+//!
+//! ## As an async stream
+//!
+//! [`stream::event_stream`](stream/fn.event_stream.html) bridges a watcher's events into a
+//! [`Stream`](https://docs.rs/futures-core/latest/futures_core/stream/trait.Stream.html), so
+//! callers on an async runtime don't need to dedicate a thread to a blocking `rx.recv()` loop.
+//!
+//! ```no_run
+//! # async fn run() -> notify::Result<()> {
+//! use futures_util::StreamExt;
+//! use notify::{stream::recommended_event_stream, Watcher, RecursiveMode};
+//!
+//! let (mut watcher, mut events) = recommended_event_stream(128)?;
+//! watcher.watch(".", RecursiveMode::Recursive)?;
+//!
+//! while let Some(event) = events.next().await {
+//!     println!("event: {:?}", event);
+//! }
+//! # Ok(())
+//! # }
+//! ```
 
 #![deny(missing_docs)]
 
-pub use config::{Config, RecursiveMode};
+pub use config::{Config, OverflowPolicy, RecursiveMode};
 pub use error::{Error, ErrorKind, Result};
 pub use event::{Event, EventKind};
+pub use filter::PathFilter;
 pub use raw_event::{op, Op, RawEvent};
 use crossbeam_channel::Sender;
 use std::convert::AsRef;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 #[cfg(target_os = "macos")]
 pub use crate::fsevent::FsEventWatcher;
@@ -120,8 +143,10 @@ pub mod inotify;
 pub mod windows;
 
 pub mod event;
+pub mod filter;
 pub mod null;
 pub mod poll;
+pub mod stream;
 
 mod config;
 mod debounce;
@@ -139,6 +164,41 @@ pub trait Watcher: Sized {
     /// Events will be sent using the provided `tx` immediately after they occur.
     fn new_immediate(tx: Sender<RawEvent>) -> Result<Self>;
 
+    /// Create a new watcher in _debounced_ mode.
+    ///
+    /// Unlike [`new_immediate`](Watcher::new_immediate), which forwards every raw event as soon
+    /// as it occurs, this mode coalesces rapid bursts of activity on the same path -- such as an
+    /// editor writing a temp file and renaming it over the target -- into a single [`Event`]
+    /// emitted once the path has seen no further activity for `delay`. A create followed by any
+    /// number of modifies and renames collapses into one create; repeated modifies collapse into
+    /// one modify.
+    ///
+    /// Events still pending when the underlying raw channel disconnects, e.g. because the whole
+    /// watcher is dropped, are flushed before the debouncing thread exits. A path's pending event
+    /// can also be flushed immediately on [`unwatch`](Watcher::unwatch), ahead of its normal
+    /// `delay`, on backends that override [`register_debounce_flush`](Watcher::register_debounce_flush).
+    ///
+    /// All bundled watchers share the same debouncing layer, so this behaves identically across
+    /// platforms.
+    fn new(tx: Sender<Event>, delay: Duration) -> Result<Self> {
+        let (raw_tx, raw_rx) = crossbeam_channel::unbounded();
+        let (flush_tx, flush_rx) = crossbeam_channel::unbounded();
+        let mut watcher = Self::new_immediate(raw_tx)?;
+        watcher.register_debounce_flush(flush_tx);
+        debounce::spawn(raw_rx, flush_rx, tx, delay);
+        Ok(watcher)
+    }
+
+    /// Register the sender a debounced watcher's [`unwatch`](Watcher::unwatch) should use to
+    /// flush a path's pending event immediately instead of leaving it to its normal `delay`.
+    ///
+    /// Called automatically by [`new`](Watcher::new). The default implementation does nothing:
+    /// an unwatched path's pending event, on backends that don't override this, simply waits out
+    /// its normal `delay` like any other pending event. Backends that want `unwatch` to flush
+    /// immediately should store `flush_tx` and send the unwatched path on it from their
+    /// [`unwatch`](Watcher::unwatch) implementation.
+    fn register_debounce_flush(&mut self, _flush_tx: Sender<PathBuf>) {}
+
     /// Begin watching a new path.
     ///
     /// If the `path` is a directory, `recursive_mode` will be evaluated. If `recursive_mode` is
@@ -156,6 +216,36 @@ pub trait Watcher: Sized {
     /// [#166]: https://github.com/passcod/notify/issues/166
     fn watch<P: AsRef<Path>>(&mut self, path: P, recursive_mode: RecursiveMode) -> Result<()>;
 
+    /// Begin watching a new path, suppressing events for any sub-path matched by `filter`.
+    ///
+    /// This is useful for subscribing to a subtree while ignoring build output, VCS directories,
+    /// or editor swap files without having to filter the resulting events yourself. See
+    /// [`PathFilter`] for the supported pattern syntax; a global filter can also be installed for
+    /// every watch via [`Config::IgnorePatterns`].
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(true)` if `filter` is actually applied by this backend.
+    /// - `Ok(false)` if this backend doesn't support filtering: the path is still watched, but
+    ///   `filter` is ignored and every event for it will be delivered unfiltered. Mirrors
+    ///   [`configure`](Watcher::configure)'s `Ok(false)` for an unsupported option, so silently
+    ///   dropping the filter can't be mistaken for it having been applied.
+    /// - `Err(notify::Error)` on failure.
+    ///
+    /// The default implementation applies no filtering and simply delegates to
+    /// [`watch`](Watcher::watch), returning `Ok(false)`; backends that can avoid the cost of
+    /// descending into ignored directories in the first place -- which also helps avoid
+    /// watch-descriptor exhaustion on large trees -- should override it.
+    fn watch_with_filter<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        recursive_mode: RecursiveMode,
+        _filter: PathFilter,
+    ) -> Result<bool> {
+        self.watch(path, recursive_mode)?;
+        Ok(false)
+    }
+
     /// Stop watching a path.
     ///
     /// # Errors
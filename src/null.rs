@@ -0,0 +1,32 @@
+//! A watcher that never watches anything and never emits events.
+//!
+//! Useful as a placeholder or in tests where a [`Watcher`] is required but no real notifications
+//! are wanted.
+
+use std::path::Path;
+
+use crossbeam_channel::Sender;
+
+use crate::{Config, RawEvent, RecursiveMode, Result, Watcher};
+
+/// A no-op [`Watcher`] implementation.
+#[derive(Debug)]
+pub struct NullWatcher;
+
+impl Watcher for NullWatcher {
+    fn new_immediate(_tx: Sender<RawEvent>) -> Result<Self> {
+        Ok(NullWatcher)
+    }
+
+    fn watch<P: AsRef<Path>>(&mut self, _path: P, _recursive_mode: RecursiveMode) -> Result<()> {
+        Ok(())
+    }
+
+    fn unwatch<P: AsRef<Path>>(&mut self, _path: P) -> Result<()> {
+        Ok(())
+    }
+
+    fn configure(&mut self, _option: Config) -> Result<bool> {
+        Ok(false)
+    }
+}
@@ -0,0 +1,401 @@
+//! A watcher that works by periodically polling the filesystem, for platforms -- or
+//! filesystems, like many network mounts -- where no event-driven backend is available.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crossbeam_channel::Sender;
+
+use crate::raw_event::{Op, RawEvent};
+use crate::{Config, OverflowPolicy, PathFilter, RecursiveMode, Result, Watcher};
+
+const DEFAULT_DELAY: Duration = Duration::from_secs(1);
+
+enum Message {
+    Watch(PathBuf, RecursiveMode, PathFilter),
+    Unwatch(PathBuf),
+    SetGlobalFilter(PathFilter),
+    SetChannelCapacity(usize),
+    SetOverflowPolicy(OverflowPolicy),
+    Stop,
+}
+
+/// A [`Watcher`] that periodically re-scans watched paths and diffs their modification times.
+pub struct PollWatcher {
+    cmd_tx: mpsc::Sender<Message>,
+    debounce_flush: Option<Sender<PathBuf>>,
+}
+
+impl Watcher for PollWatcher {
+    fn new_immediate(tx: Sender<RawEvent>) -> Result<Self> {
+        let (cmd_tx, cmd_rx) = mpsc::channel();
+        thread::spawn(move || run(cmd_rx, tx, DEFAULT_DELAY));
+        Ok(PollWatcher { cmd_tx, debounce_flush: None })
+    }
+
+    fn watch<P: AsRef<Path>>(&mut self, path: P, recursive_mode: RecursiveMode) -> Result<()> {
+        self.watch_with_filter(path, recursive_mode, PathFilter::empty()).map(|_| ())
+    }
+
+    fn watch_with_filter<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        recursive_mode: RecursiveMode,
+        filter: PathFilter,
+    ) -> Result<bool> {
+        let _ = self
+            .cmd_tx
+            .send(Message::Watch(path.as_ref().to_path_buf(), recursive_mode, filter));
+        Ok(true)
+    }
+
+    fn unwatch<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(flush_tx) = &self.debounce_flush {
+            let _ = flush_tx.send(path.clone());
+        }
+        let _ = self.cmd_tx.send(Message::Unwatch(path));
+        Ok(())
+    }
+
+    fn configure(&mut self, option: Config) -> Result<bool> {
+        match option {
+            Config::IgnorePatterns(patterns) => {
+                let filter = PathFilter::new(patterns)?;
+                let _ = self.cmd_tx.send(Message::SetGlobalFilter(filter));
+                Ok(true)
+            }
+            Config::ChannelCapacity(capacity) => {
+                let _ = self.cmd_tx.send(Message::SetChannelCapacity(capacity));
+                Ok(true)
+            }
+            Config::OverflowPolicy(policy) => {
+                let _ = self.cmd_tx.send(Message::SetOverflowPolicy(policy));
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn register_debounce_flush(&mut self, flush_tx: Sender<PathBuf>) {
+        self.debounce_flush = Some(flush_tx);
+    }
+}
+
+impl Drop for PollWatcher {
+    fn drop(&mut self) {
+        let _ = self.cmd_tx.send(Message::Stop);
+    }
+}
+
+struct Watch {
+    mode: RecursiveMode,
+    filter: PathFilter,
+    snapshot: HashMap<PathBuf, SystemTime>,
+}
+
+fn run(cmd_rx: mpsc::Receiver<Message>, tx: Sender<RawEvent>, delay: Duration) {
+    let mut watched: HashMap<PathBuf, Watch> = HashMap::new();
+    let mut global_filter = PathFilter::empty();
+    let mut channel_capacity: Option<usize> = None;
+    let mut overflow_policy = OverflowPolicy::default();
+
+    loop {
+        match cmd_rx.recv_timeout(delay) {
+            Ok(Message::Watch(path, mode, filter)) => {
+                let snapshot = snapshot(&path, mode, &filter, &global_filter);
+                watched.insert(path, Watch { mode, filter, snapshot });
+            }
+            Ok(Message::Unwatch(path)) => {
+                watched.remove(&path);
+            }
+            Ok(Message::SetGlobalFilter(filter)) => {
+                global_filter = filter;
+            }
+            Ok(Message::SetChannelCapacity(capacity)) => {
+                channel_capacity = Some(capacity);
+            }
+            Ok(Message::SetOverflowPolicy(policy)) => {
+                overflow_policy = policy;
+            }
+            Ok(Message::Stop) => return,
+            Err(mpsc::RecvTimeoutError::Disconnected) => return,
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+        }
+
+        for (root, watch) in watched.iter_mut() {
+            let current = snapshot(root, watch.mode, &watch.filter, &global_filter);
+            let burst = diff(&watch.snapshot, &current);
+            forward(root, burst, channel_capacity, overflow_policy, &tx);
+            watch.snapshot = current;
+        }
+    }
+}
+
+/// Reshape one polling cycle's burst of raw events to honor `policy` once it exceeds `capacity`.
+///
+/// `Block` leaves the burst untouched: it drops nothing, and backpressure for it is instead
+/// applied per-event by [`forward`] based on the outgoing channel's actual backlog.
+fn shape_burst(root: &Path, mut burst: Vec<RawEvent>, capacity: usize, policy: OverflowPolicy) -> Vec<RawEvent> {
+    if burst.len() <= capacity {
+        return burst;
+    }
+
+    match policy {
+        OverflowPolicy::Block => burst,
+        OverflowPolicy::DropOldest => {
+            let drop = burst.len() - capacity;
+            burst.drain(..drop);
+            burst
+        }
+        OverflowPolicy::Rescan => {
+            burst.clear();
+            burst.push(RawEvent { path: Some(root.to_path_buf()), op: Some(Op::RESCAN), cookie: None });
+            burst
+        }
+    }
+}
+
+/// Forward one polling cycle's worth of raw events, applying `policy` if the burst exceeds
+/// `capacity`.
+fn forward(
+    root: &Path,
+    burst: Vec<RawEvent>,
+    capacity: Option<usize>,
+    policy: OverflowPolicy,
+    tx: &Sender<RawEvent>,
+) {
+    let burst = match capacity {
+        Some(capacity) => shape_burst(root, burst, capacity, policy),
+        None => burst,
+    };
+
+    for raw in burst {
+        if let (Some(capacity), OverflowPolicy::Block) = (capacity, policy) {
+            // Genuine backpressure, independent of whether the caller's own channel was
+            // constructed bounded: `Sender::len()` reports the outstanding backlog either way, so
+            // block here until it has actually drained below `capacity`.
+            while tx.len() >= capacity {
+                thread::sleep(Duration::from_millis(1));
+            }
+        }
+        let _ = tx.send(raw);
+    }
+}
+
+fn snapshot(
+    root: &Path,
+    mode: RecursiveMode,
+    filter: &PathFilter,
+    global_filter: &PathFilter,
+) -> HashMap<PathBuf, SystemTime> {
+    let mut out = HashMap::new();
+    visit(root, mode, filter, global_filter, &mut out, true);
+    out
+}
+
+/// Snapshot `path` into `out`, recursing into subdirectories only when `mode.is_recursive()`.
+///
+/// `is_root` marks the initial call for the watched path itself: even in `NonRecursive` mode, the
+/// watch's own immediate children must still be listed one level deep -- per
+/// [`Watcher::watch`](crate::Watcher::watch)'s contract -- just without recursing any further into
+/// them.
+fn visit(
+    path: &Path,
+    mode: RecursiveMode,
+    filter: &PathFilter,
+    global_filter: &PathFilter,
+    out: &mut HashMap<PathBuf, SystemTime>,
+    is_root: bool,
+) {
+    if filter.is_ignored(path) || global_filter.is_ignored(path) {
+        // Short-circuit: an ignored directory's contents never get visited, so no snapshot
+        // entries -- and no inner watches, were this a descriptor-based backend -- are created
+        // for it.
+        return;
+    }
+
+    let metadata = match fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(_) => return,
+    };
+    if let Ok(modified) = metadata.modified() {
+        out.insert(path.to_path_buf(), modified);
+    }
+    if metadata.is_dir() && (mode.is_recursive() || is_root) {
+        if let Ok(entries) = fs::read_dir(path) {
+            for entry in entries.flatten() {
+                visit(&entry.path(), mode, filter, global_filter, out, false);
+            }
+        }
+    }
+}
+
+/// Diff two snapshots into a burst of raw events, ordered oldest-first by the timestamp each
+/// event is actually about.
+///
+/// `current`/`last` are `HashMap`s with unspecified iteration order, so the burst must be sorted
+/// explicitly -- otherwise [`shape_burst`]'s `DropOldest` policy would drop events in whatever
+/// arbitrary order the maps happened to iterate in, rather than the oldest ones.
+fn diff(last: &HashMap<PathBuf, SystemTime>, current: &HashMap<PathBuf, SystemTime>) -> Vec<RawEvent> {
+    let mut burst: Vec<(SystemTime, RawEvent)> = Vec::new();
+
+    for (path, modified) in current {
+        match last.get(path) {
+            None => burst.push((*modified, raw(path.clone(), Op::CREATE))),
+            Some(previous) if previous != modified => burst.push((*modified, raw(path.clone(), Op::WRITE))),
+            _ => {}
+        }
+    }
+    for (path, modified) in last {
+        if !current.contains_key(path) {
+            burst.push((*modified, raw(path.clone(), Op::REMOVE)));
+        }
+    }
+
+    burst.sort_by_key(|(modified, _)| *modified);
+    burst.into_iter().map(|(_, raw)| raw).collect()
+}
+
+fn raw(path: PathBuf, op: Op) -> RawEvent {
+    RawEvent { path: Some(path), op: Some(op), cookie: None }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    fn burst(n: usize) -> Vec<RawEvent> {
+        (0..n).map(|i| raw(PathBuf::from(format!("/file{i}")), Op::WRITE)).collect()
+    }
+
+    /// A scratch directory under the system temp dir, removed on drop.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new() -> Self {
+            static COUNTER: AtomicUsize = AtomicUsize::new(0);
+            let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+            let path = std::env::temp_dir().join(format!("notify-poll-test-{}-{id}", std::process::id()));
+            fs::create_dir_all(&path).unwrap();
+            TempDir(path)
+        }
+    }
+
+    impl std::ops::Deref for TempDir {
+        type Target = Path;
+        fn deref(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn snapshot_non_recursive_includes_immediate_children_but_not_grandchildren() {
+        let dir = TempDir::new();
+        fs::write(dir.join("child.txt"), b"hi").unwrap();
+        let nested = dir.join("nested");
+        fs::create_dir(&nested).unwrap();
+        fs::write(nested.join("grandchild.txt"), b"hi").unwrap();
+
+        let snap = snapshot(&dir, RecursiveMode::NonRecursive, &PathFilter::empty(), &PathFilter::empty());
+
+        assert!(snap.contains_key(&dir.join("child.txt")));
+        assert!(snap.contains_key(&nested));
+        assert!(!snap.contains_key(&nested.join("grandchild.txt")));
+    }
+
+    #[test]
+    fn snapshot_recursive_includes_grandchildren() {
+        let dir = TempDir::new();
+        let nested = dir.join("nested");
+        fs::create_dir(&nested).unwrap();
+        fs::write(nested.join("grandchild.txt"), b"hi").unwrap();
+
+        let snap = snapshot(&dir, RecursiveMode::Recursive, &PathFilter::empty(), &PathFilter::empty());
+
+        assert!(snap.contains_key(&nested.join("grandchild.txt")));
+    }
+
+    #[test]
+    fn shape_burst_leaves_small_bursts_untouched() {
+        let shaped = shape_burst(Path::new("/root"), burst(2), 5, OverflowPolicy::DropOldest);
+        assert_eq!(shaped.len(), 2);
+    }
+
+    #[test]
+    fn shape_burst_block_keeps_the_whole_backlog() {
+        let shaped = shape_burst(Path::new("/root"), burst(10), 3, OverflowPolicy::Block);
+        assert_eq!(shaped.len(), 10);
+    }
+
+    #[test]
+    fn shape_burst_drop_oldest_keeps_only_the_newest_capacity_events() {
+        let shaped = shape_burst(Path::new("/root"), burst(5), 2, OverflowPolicy::DropOldest);
+        assert_eq!(shaped.len(), 2);
+        assert_eq!(shaped[0].path, Some(PathBuf::from("/file3")));
+        assert_eq!(shaped[1].path, Some(PathBuf::from("/file4")));
+    }
+
+    #[test]
+    fn shape_burst_rescan_collapses_to_one_synthetic_event() {
+        let shaped = shape_burst(Path::new("/root"), burst(5), 2, OverflowPolicy::Rescan);
+        assert_eq!(shaped.len(), 1);
+        assert_eq!(shaped[0].path, Some(PathBuf::from("/root")));
+        assert_eq!(shaped[0].op, Some(Op::RESCAN));
+    }
+
+    #[test]
+    fn diff_orders_the_burst_oldest_first() {
+        let t0 = SystemTime::UNIX_EPOCH;
+        let t1 = t0 + Duration::from_secs(1);
+        let t2 = t0 + Duration::from_secs(2);
+
+        let mut last = HashMap::new();
+        last.insert(PathBuf::from("/removed"), t0);
+        last.insert(PathBuf::from("/unchanged"), t0);
+
+        let mut current = HashMap::new();
+        current.insert(PathBuf::from("/unchanged"), t0);
+        current.insert(PathBuf::from("/newest"), t2);
+        current.insert(PathBuf::from("/oldest"), t1);
+
+        let burst = diff(&last, &current);
+        let paths: Vec<_> = burst.iter().map(|raw| raw.path.clone().unwrap()).collect();
+        assert_eq!(
+            paths,
+            vec![PathBuf::from("/removed"), PathBuf::from("/oldest"), PathBuf::from("/newest")]
+        );
+    }
+
+    #[test]
+    fn forward_block_waits_for_the_channel_to_drain_below_capacity() {
+        let (tx, rx) = crossbeam_channel::unbounded();
+        // Pre-fill the channel to the configured capacity so any further send must wait.
+        let _ = tx.send(raw(PathBuf::from("/already-queued"), Op::WRITE));
+
+        let root = PathBuf::from("/root");
+        let pending = burst(1);
+        let tx_clone = tx.clone();
+        let handle = thread::spawn(move || forward(&root, pending, Some(1), OverflowPolicy::Block, &tx_clone));
+
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(tx.len(), 1, "forward should still be blocked while the channel is at capacity");
+
+        // Draining the backlog should let the blocked send through.
+        rx.recv().unwrap();
+        handle.join().unwrap();
+        assert_eq!(tx.len(), 1);
+    }
+}
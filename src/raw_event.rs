@@ -0,0 +1,82 @@
+//! The low-level, platform-near event type and its operation flags.
+
+use std::path::PathBuf;
+
+/// Bitflags describing the filesystem operation(s) a [`RawEvent`] is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Op(u32);
+
+impl Op {
+    /// Something was created.
+    pub const CREATE: Op = Op(0b0000_0001);
+    /// Something was written to or otherwise modified.
+    pub const WRITE: Op = Op(0b0000_0010);
+    /// Something was removed.
+    pub const REMOVE: Op = Op(0b0000_0100);
+    /// Something was renamed.
+    pub const RENAME: Op = Op(0b0000_1000);
+    /// Metadata (permissions, timestamps, ...) changed.
+    pub const METADATA: Op = Op(0b0001_0000);
+    /// A backend had to drop events during a burst; a rescan of the affected subtree is
+    /// recommended. See [`Config::OverflowPolicy`](crate::Config::OverflowPolicy).
+    pub const RESCAN: Op = Op(0b0010_0000);
+
+    /// An `Op` with no flags set.
+    pub const fn empty() -> Op {
+        Op(0)
+    }
+
+    /// Returns `true` if `self` has all of the flags set in `other`.
+    pub const fn contains(self, other: Op) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for Op {
+    type Output = Op;
+
+    fn bitor(self, rhs: Op) -> Op {
+        Op(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for Op {
+    fn bitor_assign(&mut self, rhs: Op) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Re-exports [`Op`] under its conventional module path, `notify::op::Op`.
+pub mod op {
+    pub use super::Op;
+}
+
+/// A raw, platform-near filesystem event as delivered by
+/// [`Watcher::new_immediate`](crate::Watcher::new_immediate).
+#[derive(Debug, Clone)]
+pub struct RawEvent {
+    /// The path the event is about, if known.
+    pub path: Option<PathBuf>,
+    /// The operation(s) that occurred, if known.
+    pub op: Option<Op>,
+    /// A backend-specific cookie used to associate related events, such as the two halves of a
+    /// rename.
+    pub cookie: Option<u32>,
+}
+
+impl Op {
+    /// Classify this operation into the coarser, public [`EventKind`](crate::EventKind).
+    pub(crate) fn classify(self) -> crate::EventKind {
+        if self.contains(Op::RESCAN) {
+            crate::EventKind::Rescan
+        } else if self.contains(Op::REMOVE) {
+            crate::EventKind::Remove
+        } else if self.contains(Op::CREATE) {
+            crate::EventKind::Create
+        } else if self.contains(Op::WRITE) || self.contains(Op::METADATA) || self.contains(Op::RENAME) {
+            crate::EventKind::Modify
+        } else {
+            crate::EventKind::Any
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! An async [`Stream`] adapter bridging a watcher's synchronous event channel.
+//!
+//! This lets callers integrate the library with an async runtime without spinning their own
+//! blocking `rx.recv()` loop on a dedicated thread.
+//!
+//! Requires the `sink` feature on both `futures-channel` and `futures-util`, since
+//! [`mpsc::Sender::send`] is only available through [`futures_util::SinkExt`]'s blanket impl over
+//! `futures_channel::mpsc::Sender`'s `Sink`:
+//!
+//! ```toml
+//! [dependencies]
+//! futures-channel = { version = "0.3", features = ["sink"] }
+//! futures-util = { version = "0.3", features = ["sink"] }
+//! ```
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_channel::mpsc;
+use futures_core::Stream;
+use futures_executor::block_on;
+use futures_util::SinkExt;
+
+use crate::event::Event;
+use crate::raw_event::RawEvent;
+use crate::{RecommendedWatcher, Result, Watcher};
+
+/// A [`Stream`] of [`Result<Event>`] bridged from a watcher's synchronous event channel.
+///
+/// Backpressure is applied via a bounded internal buffer: once full, the bridging thread blocks
+/// sending further events until the stream is polled and makes room. A raw event that can't be
+/// classified (one with no path) is surfaced as an `Err` item rather than silently dropped.
+/// Errors from the watcher's own methods, such as [`Watcher::watch`], are returned to their
+/// caller and do not appear in the stream.
+pub struct EventStream {
+    rx: mpsc::Receiver<Result<Event>>,
+}
+
+impl Stream for EventStream {
+    type Item = Result<Event>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.rx).poll_next(cx)
+    }
+}
+
+/// Create a watcher of type `W` and bridge its raw event channel into a backpressured
+/// [`EventStream`].
+///
+/// `capacity` bounds how many events may be buffered between the bridging thread and the async
+/// consumer before the bridging thread blocks waiting for the stream to be polled. The channel
+/// from the watcher to the bridging thread is unbounded, so the watcher's own thread never blocks.
+pub fn event_stream<W: Watcher>(capacity: usize) -> Result<(W, EventStream)> {
+    let (raw_tx, raw_rx) = crossbeam_channel::unbounded();
+    let watcher = W::new_immediate(raw_tx)?;
+    let (item_tx, item_rx) = mpsc::channel(capacity);
+
+    std::thread::spawn(move || bridge(raw_rx, item_tx));
+
+    Ok((watcher, EventStream { rx: item_rx }))
+}
+
+/// Drain `raw_rx`, classifying and forwarding each event onto `item_tx` until either side
+/// disconnects.
+fn bridge(raw_rx: crossbeam_channel::Receiver<RawEvent>, mut item_tx: mpsc::Sender<Result<Event>>) {
+    while let Ok(raw) = raw_rx.recv() {
+        let item = to_event(raw);
+        if block_on(item_tx.send(item)).is_err() {
+            break;
+        }
+    }
+}
+
+/// Convenience wrapper around [`event_stream`] using the platform's
+/// [`RecommendedWatcher`](crate::RecommendedWatcher).
+pub fn recommended_event_stream(capacity: usize) -> Result<(RecommendedWatcher, EventStream)> {
+    event_stream(capacity)
+}
+
+fn to_event(raw: RawEvent) -> Result<Event> {
+    Event::try_from_raw(raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use futures_util::StreamExt;
+
+    use super::*;
+    use crate::event::EventKind;
+    use crate::raw_event::Op;
+
+    #[test]
+    fn bridge_forwards_a_full_send_and_receive_cycle() {
+        let (raw_tx, raw_rx) = crossbeam_channel::unbounded();
+        let (item_tx, mut item_rx) = mpsc::channel(1);
+
+        raw_tx
+            .send(RawEvent { path: Some(PathBuf::from("/a")), op: Some(Op::CREATE), cookie: None })
+            .unwrap();
+        drop(raw_tx);
+
+        let handle = std::thread::spawn(move || bridge(raw_rx, item_tx));
+
+        let event = block_on(item_rx.next()).unwrap().unwrap();
+        assert_eq!(event, Event::new(EventKind::Create, PathBuf::from("/a")));
+        assert!(block_on(item_rx.next()).is_none());
+
+        handle.join().unwrap();
+    }
+}